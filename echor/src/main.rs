@@ -0,0 +1,137 @@
+// import the clap::App struct
+use clap::{App, Arg};
+use std::io::{self, Write};
+
+fn main() {
+    // create a new App
+    let matches = App::new("echor")
+        .version("0.1.0")
+        .author("Dave Tang <me@davetang.org>")
+        .about("Implementation of echo using Rust")
+        .arg(
+            // create new Arg called text
+            Arg::with_name("text")
+                .value_name("TEXT")
+                .help("Input text")
+                .required(true)
+                .min_values(1),
+        )
+        .arg(
+            // create new Arg called omit_newline
+            Arg::with_name("omit_newline")
+                .long("omit_newline")
+                .short("n")
+                .help("Do not print newline")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("escape")
+                .short("e")
+                .help("Enable interpretation of backslash escapes")
+                .takes_value(false)
+                .overrides_with("no_escape"),
+        )
+        .arg(
+            Arg::with_name("no_escape")
+                .short("E")
+                .help("Disable interpretation of backslash escapes (default)")
+                .takes_value(false)
+                .overrides_with("escape"),
+        )
+        // tells the App to parse the args
+        .get_matches();
+
+    // pretty print for debugging
+    // println!("{:#?}", matches);
+
+    // only use unwrap if we are sure that
+    // the value will not be None
+    let text = matches.values_of_lossy("text").unwrap();
+    let omit_newline = matches.is_present("omit_newline");
+    let escape = matches.is_present("escape");
+
+    // join will insert a str between all elements of a
+    // vector (in this case all the command args)
+    let joined = text.join(" ");
+    let output = if escape { unescape(&joined) } else { joined.into_bytes() };
+
+    // if is an expression in Rust, which means it returns a value
+    let newline: &[u8] = if omit_newline { b"" } else { b"\n" };
+    let mut stdout = io::stdout();
+    stdout.write_all(&output).unwrap();
+    stdout.write_all(newline).unwrap();
+}
+
+// --------------------------------------------------
+// interprets GNU echo's backslash escapes (\\, \n, \t, \r, \0NNN octal,
+// \xHH hex); an escape this function does not recognize is left as-is
+fn unescape(input: &str) -> Vec<u8> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] != b'\\' || i + 1 >= bytes.len() {
+            out.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+
+        match bytes[i + 1] {
+            b'\\' => {
+                out.push(b'\\');
+                i += 2;
+            }
+            b'n' => {
+                out.push(b'\n');
+                i += 2;
+            }
+            b't' => {
+                out.push(b'\t');
+                i += 2;
+            }
+            b'r' => {
+                out.push(b'\r');
+                i += 2;
+            }
+            b'0' => {
+                let digits: String = bytes[i + 2..]
+                    .iter()
+                    .take(3)
+                    .take_while(|b| (b'0'..=b'7').contains(b))
+                    .map(|&b| b as char)
+                    .collect();
+                // parse into a wider int first and truncate to the low 8
+                // bits, so an out-of-range escape like \0777 (511) wraps the
+                // way GNU echo does instead of silently becoming NUL
+                let value = u32::from_str_radix(&digits, 8).unwrap_or(0);
+                out.push(value as u8);
+                i += 2 + digits.len();
+            }
+            b'x' => {
+                let digits: String = bytes[i + 2..]
+                    .iter()
+                    .take(2)
+                    .take_while(|b| b.is_ascii_hexdigit())
+                    .map(|&b| b as char)
+                    .collect();
+                if digits.is_empty() {
+                    out.push(bytes[i]);
+                    out.push(bytes[i + 1]);
+                    i += 2;
+                } else {
+                    out.push(u8::from_str_radix(&digits, 16).unwrap_or(0));
+                    i += 2 + digits.len();
+                }
+            }
+            other => {
+                // unrecognized escape: pass both bytes through literally
+                out.push(b'\\');
+                out.push(other);
+                i += 2;
+            }
+        }
+    }
+
+    out
+}
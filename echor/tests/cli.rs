@@ -67,3 +67,53 @@ fn hello1_no_newline() -> TestResult {
 fn hello2_no_newline() -> TestResult {
     run(&["-n", "Hello", "there"], "tests/expected/hello2.n.txt")
 }
+
+// --------------------------------------------------
+#[test]
+fn escape_tab() -> TestResult {
+    run(&["Hello\\tthere", "-e"], "tests/expected/escape_tab.txt")
+}
+
+// --------------------------------------------------
+#[test]
+fn escape_newline() -> TestResult {
+    run(
+        &["Hello\\nthere", "-e"],
+        "tests/expected/escape_newline.txt",
+    )
+}
+
+// --------------------------------------------------
+#[test]
+fn escape_invalid_passed_through() -> TestResult {
+    run(
+        &["Hello\\qthere", "-e"],
+        "tests/expected/escape_invalid.txt",
+    )
+}
+
+// --------------------------------------------------
+#[test]
+fn escape_with_no_newline() -> TestResult {
+    run(
+        &["Hello\\tthere", "-e", "-n"],
+        "tests/expected/escape_tab_no_newline.txt",
+    )
+}
+
+// --------------------------------------------------
+#[test]
+fn no_escape_by_default() -> TestResult {
+    run(&["Hello\\tthere"], "tests/expected/no_escape.txt")
+}
+
+// --------------------------------------------------
+// \0401 is 257 in decimal (out of u8 range); GNU echo truncates to the low
+// 8 bits (257 % 256 = 1) instead of emitting NUL
+#[test]
+fn escape_octal_truncates_overflow() -> TestResult {
+    run(
+        &["\\0401", "-e"],
+        "tests/expected/escape_octal_overflow.txt",
+    )
+}
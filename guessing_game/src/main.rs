@@ -2,71 +2,244 @@
    https://doc.rust-lang.org/book/ch02-00-guessing-game-tutorial.html
 */
 
-// input/output library from the standard library
-use std::io;
-use rand::Rng;
-// Ordering type is an enum and has the variants Less, Greater, and Equal
+// follows the clap::App/Arg pattern used by the echor binary
+use clap::{App, Arg};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::cmp::Ordering;
+use std::error::Error;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+type MyResult<T> = Result<T, Box<dyn Error>>;
+
+// --------------------------------------------------
+#[derive(Debug)]
+struct Config {
+    min: i32,
+    max: i32,
+    max_attempts: u32,
+    seed: Option<u64>,
+    history_file: PathBuf,
+    stats: bool,
+}
+
+// --------------------------------------------------
+fn get_args() -> Config {
+    let matches = App::new("guessing_game")
+        .version("0.1.0")
+        .author("Dave Tang <me@davetang.org>")
+        .about("Guess the secret number")
+        .arg(
+            Arg::with_name("min")
+                .long("min")
+                .value_name("MIN")
+                .help("Lower bound of the secret number (inclusive)")
+                .default_value("1"),
+        )
+        .arg(
+            Arg::with_name("max")
+                .long("max")
+                .value_name("MAX")
+                .help("Upper bound of the secret number (inclusive)")
+                .default_value("100"),
+        )
+        .arg(
+            Arg::with_name("max_attempts")
+                .long("max-attempts")
+                .value_name("ATTEMPTS")
+                .help("Number of guesses allowed before the game ends")
+                .default_value("10"),
+        )
+        .arg(
+            Arg::with_name("seed")
+                .long("seed")
+                .value_name("SEED")
+                .help("Seed the RNG for a reproducible secret number"),
+        )
+        .arg(
+            Arg::with_name("history_file")
+                .long("history-file")
+                .value_name("FILE")
+                .help("Path to the game history file (default: ~/.guessing_game_history)"),
+        )
+        .arg(
+            Arg::with_name("stats")
+                .long("stats")
+                .help("Report best/worst/average guess counts instead of playing")
+                .takes_value(false),
+        )
+        .get_matches();
+
+    let min = parse_i32(matches.value_of("min").unwrap(), "min");
+    let max = parse_i32(matches.value_of("max").unwrap(), "max");
+    let max_attempts: u32 = matches
+        .value_of("max_attempts")
+        .unwrap()
+        .parse::<u32>()
+        .ok()
+        .filter(|&n| n > 0)
+        .unwrap_or_else(|| die("--max-attempts must be a positive integer"));
+    let seed = matches.value_of("seed").map(|val| {
+        val.parse()
+            .unwrap_or_else(|_| die("--seed must be an unsigned integer"))
+    });
+    let history_file = matches
+        .value_of("history_file")
+        .map(PathBuf::from)
+        .unwrap_or_else(default_history_path);
+    let stats = matches.is_present("stats");
+
+    if !stats && min >= max {
+        die(&format!("--min ({min}) must be less than --max ({max})"));
+    }
+
+    Config {
+        min,
+        max,
+        max_attempts,
+        seed,
+        history_file,
+        stats,
+    }
+}
+
+// --------------------------------------------------
+fn default_history_path() -> PathBuf {
+    match std::env::var("HOME") {
+        Ok(home) => PathBuf::from(home).join(".guessing_game_history"),
+        Err(_) => PathBuf::from(".guessing_game_history"),
+    }
+}
+
+// --------------------------------------------------
+fn parse_i32(val: &str, name: &str) -> i32 {
+    val.parse()
+        .unwrap_or_else(|_| die(&format!("--{name} must be an integer")))
+}
+
+// --------------------------------------------------
+// mirrors the USAGE-style stderr message clap prints on a parse failure so
+// our own validation errors look the same to callers (and to tests)
+fn die(msg: &str) -> ! {
+    eprintln!(
+        "USAGE: guessing_game [--min MIN] [--max MAX] [--max-attempts ATTEMPTS] [--seed SEED] [--history-file FILE] [--stats]\n{msg}"
+    );
+    process::exit(1);
+}
+
+// --------------------------------------------------
 fn main() {
-    println!("Guess the number!");
+    let config = get_args();
+
+    if let Err(e) = run(config) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+// --------------------------------------------------
+fn run(config: Config) -> MyResult<()> {
+    if config.stats {
+        print_stats(&config.history_file)
+    } else {
+        play(&config)
+    }
+}
 
-    // start..=end is inclusive on the lower and upper bounds
-    let secret_number = rand::thread_rng().gen_range(1..=100);
+// --------------------------------------------------
+fn play(config: &Config) -> MyResult<()> {
+    println!(
+        "Guess the number! Pick a number between {} and {}.",
+        config.min, config.max
+    );
 
-    // println!("The secret number is: {secret_number}");
+    let secret_number = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed).gen_range(config.min..=config.max),
+        None => rand::thread_rng().gen_range(config.min..=config.max),
+    };
+
+    let mut guesses = 0;
 
-    // the loop keyword creates an infinite loop
     loop {
+        if guesses >= config.max_attempts {
+            println!("Out of attempts; the number was {secret_number}");
+            append_history(config, guesses, false)?;
+            process::exit(1);
+        }
+
         println!("Please input your guess.");
 
-        // let statement to create variable called guess
-        // mut creates a mutable variable
-        // String::new is a function that returns a new instance of a String
         let mut guess = String::new();
 
-        // the stdin function handles user input
-        // the read_line method works on the standard input handle
-        // passing &mut guess to tell function what string to store the input
-        // the & indicates that the argument is a reference
-        // read_line puts stdin into a string and also returns a Result value
-        // Result is an enumeration, enum, a type that can be in one of
-        // multiple possible states; each possible state is a variant
-        // Result's variants are Ok and Err
-        // Values of the Result type, like values of any type, have methods
-        // An instance of Result has an expect method
-        // If this instance of Result is an Err value, expect will cause the
-        // program to crash and display the message
         io::stdin()
             .read_line(&mut guess)
             .expect("Failed to read line");
 
-        // shadow the previous value of guess with a new one
-        // shadowing lets us reuse the guess variable
-        // trim() removes whitespace at the beginning and end
-        // parse() method on strings converts a string to another type
-        // the : after guess allows us to annotate the variable's type
-        // u32 is an unsigned, 32-bit integer
-        let guess: u32 = match guess.trim().parse() {
+        let guess: i32 = match guess.trim().parse() {
             Ok(num) => num,
             Err(_) => continue,
         };
 
+        guesses += 1;
+
         println!("You guessed: {guess}");
 
-        // the cmp method compares two values; here guess and secret_number
-        // then it returns a variant of the Ordering enum
-        // the match expression decides what to do based on the variant of Ordering
-        // a match expression is made up of arms
-        // an arm consists of a pattern to match against and the code to run
         match guess.cmp(&secret_number) {
             Ordering::Less => println!("Too small!"),
             Ordering::Greater => println!("Too big!"),
             Ordering::Equal => {
-                println!("You win!");
-                // exit loop
-                break;
+                println!("Solved in {guesses} guesses");
+                append_history(config, guesses, true)?;
+                process::exit(0);
             }
         }
     }
 }
+
+// --------------------------------------------------
+// appends a single tab-separated record: timestamp, min, max, guesses, result
+fn append_history(config: &Config, guesses: u32, won: bool) -> MyResult<()> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let result = if won { "win" } else { "loss" };
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&config.history_file)?;
+    writeln!(
+        file,
+        "{}\t{}\t{}\t{}\t{}",
+        timestamp, config.min, config.max, guesses, result
+    )?;
+    Ok(())
+}
+
+// --------------------------------------------------
+fn print_stats(history_file: &PathBuf) -> MyResult<()> {
+    let contents = std::fs::read_to_string(history_file).unwrap_or_default();
+    let guess_counts: Vec<u32> = contents
+        .lines()
+        .filter_map(|line| line.split('\t').nth(3))
+        .filter_map(|count| count.parse().ok())
+        .collect();
+
+    if guess_counts.is_empty() {
+        println!("No games recorded yet.");
+        return Ok(());
+    }
+
+    let total = guess_counts.len();
+    let best = guess_counts.iter().min().unwrap();
+    let worst = guess_counts.iter().max().unwrap();
+    let average = guess_counts.iter().sum::<u32>() as f64 / total as f64;
+
+    println!("Total games played: {total}");
+    println!("Best guess count: {best}");
+    println!("Worst guess count: {worst}");
+    println!("Average guesses: {average:.2}");
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::NamedTempFile;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+// --------------------------------------------------
+#[test]
+fn dies_bad_range() -> TestResult {
+    Command::cargo_bin("guessing_game")?
+        .args(["--min", "10", "--max", "5"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("USAGE"));
+    Ok(())
+}
+
+// --------------------------------------------------
+#[test]
+fn stats_skips_range_validation() -> TestResult {
+    let history = NamedTempFile::new()?;
+    Command::cargo_bin("guessing_game")?
+        .args([
+            "--stats",
+            "--min",
+            "5",
+            "--max",
+            "1",
+            "--history-file",
+            history.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No games recorded yet."));
+    Ok(())
+}
+
+// --------------------------------------------------
+#[test]
+fn loses_when_out_of_attempts() -> TestResult {
+    let history = NamedTempFile::new()?;
+    // --min/--max pin the secret to {1, 2}; guessing 9999 (well outside the
+    // range) can never match it, so the loss is deterministic regardless of
+    // what --seed happens to draw
+    Command::cargo_bin("guessing_game")?
+        .args([
+            "--min",
+            "1",
+            "--max",
+            "2",
+            "--seed",
+            "7",
+            "--max-attempts",
+            "1",
+            "--history-file",
+            history.path().to_str().unwrap(),
+        ])
+        .write_stdin("9999\n")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Out of attempts"));
+    Ok(())
+}
+
+// --------------------------------------------------
+#[test]
+fn wins_by_exhausting_the_narrow_range() -> TestResult {
+    let history = NamedTempFile::new()?;
+    // --min/--max pin the secret to {42, 43}; guessing both in turn
+    // guarantees a win on or before the second attempt regardless of which
+    // value --seed draws, covering the success path deterministically
+    Command::cargo_bin("guessing_game")?
+        .args([
+            "--min",
+            "42",
+            "--max",
+            "43",
+            "--seed",
+            "7",
+            "--max-attempts",
+            "2",
+            "--history-file",
+            history.path().to_str().unwrap(),
+        ])
+        .write_stdin("42\n43\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Solved in"));
+    Ok(())
+}
+
+// --------------------------------------------------
+#[test]
+fn stats_with_no_games() -> TestResult {
+    let history = NamedTempFile::new()?;
+    Command::cargo_bin("guessing_game")?
+        .args([
+            "--stats",
+            "--history-file",
+            history.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No games recorded yet."));
+    Ok(())
+}
+
+// --------------------------------------------------
+#[test]
+fn stats_after_a_game() -> TestResult {
+    let history = NamedTempFile::new()?;
+
+    Command::cargo_bin("guessing_game")?
+        .args([
+            "--min",
+            "1",
+            "--max",
+            "2",
+            "--seed",
+            "7",
+            "--max-attempts",
+            "1",
+            "--history-file",
+            history.path().to_str().unwrap(),
+        ])
+        .write_stdin("9999\n")
+        .assert()
+        .failure();
+
+    Command::cargo_bin("guessing_game")?
+        .args([
+            "--stats",
+            "--history-file",
+            history.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Total games played: 1"));
+
+    Ok(())
+}
@@ -1,21 +1,386 @@
-use std::io::BufReader;
+use clap::{App, Arg};
+use std::error::Error;
 use std::fs::File;
-use bio::io::fasta;
+use std::io::{self, BufReader, Read};
 
+use bio::io::{fasta, fastq};
+
+type MyResult<T> = Result<T, Box<dyn Error>>;
+
+// --------------------------------------------------
+#[derive(Debug)]
+enum Format {
+    Fasta,
+    Fastq,
+}
+
+// --------------------------------------------------
+#[derive(Debug)]
+enum Mode {
+    Stats,
+    RevComp,
+    Transcribe,
+}
+
+// --------------------------------------------------
+#[derive(Debug)]
+struct Config {
+    input: Option<String>,
+    format: Format,
+    mode: Mode,
+}
+
+// --------------------------------------------------
+struct SeqRecord {
+    id: String,
+    desc: Option<String>,
+    seq: Vec<u8>,
+}
+
+// --------------------------------------------------
+struct SeqStats {
+    id: String,
+    desc: String,
+    length: usize,
+    gc_content: f64,
+    a: usize,
+    c: usize,
+    g: usize,
+    t: usize,
+    n: usize,
+    other: usize,
+}
+
+// --------------------------------------------------
+fn get_args() -> Config {
+    let matches = App::new("fastartools")
+        .version("0.1.0")
+        .author("Dave Tang <me@davetang.org>")
+        .about("Reports sequence statistics, or transforms FASTA/FASTQ records")
+        .arg(
+            Arg::with_name("input")
+                .value_name("FILE")
+                .help("Input file (defaults to stdin)"),
+        )
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .value_name("FORMAT")
+                .help("Input format")
+                .possible_values(&["fasta", "fastq"])
+                .default_value("fasta"),
+        )
+        .arg(
+            Arg::with_name("revcomp")
+                .long("revcomp")
+                .help("Emit the reverse complement of each record as FASTA")
+                .takes_value(false)
+                .conflicts_with("transcribe"),
+        )
+        .arg(
+            Arg::with_name("transcribe")
+                .long("transcribe")
+                .help("Emit each record transcribed from DNA to RNA as FASTA")
+                .takes_value(false)
+                .conflicts_with("revcomp"),
+        )
+        .get_matches();
+
+    let format = match matches.value_of("format").unwrap() {
+        "fastq" => Format::Fastq,
+        _ => Format::Fasta,
+    };
+
+    let mode = if matches.is_present("revcomp") {
+        Mode::RevComp
+    } else if matches.is_present("transcribe") {
+        Mode::Transcribe
+    } else {
+        Mode::Stats
+    };
+
+    Config {
+        input: matches.value_of("input").map(String::from),
+        format,
+        mode,
+    }
+}
+
+// --------------------------------------------------
+fn open(input: &Option<String>) -> MyResult<Box<dyn Read>> {
+    match input {
+        Some(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+        None => Ok(Box::new(BufReader::new(io::stdin()))),
+    }
+}
+
+// --------------------------------------------------
+fn read_records(config: &Config) -> MyResult<Vec<SeqRecord>> {
+    let source = open(&config.input)?;
+    let mut records = vec![];
+
+    match config.format {
+        Format::Fasta => {
+            for result in fasta::Reader::new(source).records() {
+                let record = result?;
+                records.push(SeqRecord {
+                    id: record.id().to_string(),
+                    desc: record.desc().map(str::to_string),
+                    seq: record.seq().to_vec(),
+                });
+            }
+        }
+        Format::Fastq => {
+            for result in fastq::Reader::new(source).records() {
+                let record = result?;
+                records.push(SeqRecord {
+                    id: record.id().to_string(),
+                    desc: record.desc().map(str::to_string),
+                    seq: record.seq().to_vec(),
+                });
+            }
+        }
+    }
+
+    Ok(records)
+}
+
+// --------------------------------------------------
+fn base_stats(seq: &[u8]) -> (usize, usize, usize, usize, usize, usize, f64) {
+    let (mut a, mut c, mut g, mut t, mut n, mut other) = (0, 0, 0, 0, 0, 0);
+
+    for &base in seq {
+        match base.to_ascii_uppercase() {
+            b'A' => a += 1,
+            b'C' => c += 1,
+            b'G' => g += 1,
+            b'T' => t += 1,
+            b'N' => n += 1,
+            _ => other += 1,
+        }
+    }
+
+    let acgt = a + c + g + t;
+    let gc_content = if acgt > 0 {
+        (g + c) as f64 / acgt as f64
+    } else {
+        0.0
+    };
+
+    (a, c, g, t, n, other, gc_content)
+}
+
+// --------------------------------------------------
+// sorts lengths descending and reports the length of the record at which the
+// running sum first reaches half of the total assembly length
+fn n50(lengths: &[usize]) -> usize {
+    let mut lengths = lengths.to_vec();
+    lengths.sort_unstable_by(|a, b| b.cmp(a));
+
+    let total: usize = lengths.iter().sum();
+    let half = total as f64 / 2.0;
+
+    let mut running = 0;
+    for length in lengths {
+        running += length;
+        if running as f64 >= half {
+            return length;
+        }
+    }
+
+    0
+}
+
+// --------------------------------------------------
+fn print_stats(records: &[SeqRecord]) {
+    let stats: Vec<SeqStats> = records
+        .iter()
+        .map(|r| {
+            let (a, c, g, t, n, other, gc_content) = base_stats(&r.seq);
+            SeqStats {
+                id: r.id.clone(),
+                desc: r.desc.clone().unwrap_or_default(),
+                length: r.seq.len(),
+                gc_content,
+                a,
+                c,
+                g,
+                t,
+                n,
+                other,
+            }
+        })
+        .collect();
+
+    let total_length: usize = stats.iter().map(|r| r.length).sum();
+    let total_gc: usize = stats.iter().map(|r| r.g + r.c).sum();
+    let total_acgt: usize = stats.iter().map(|r| r.a + r.c + r.g + r.t).sum();
+    let overall_gc = if total_acgt > 0 {
+        total_gc as f64 / total_acgt as f64
+    } else {
+        0.0
+    };
+    let lengths: Vec<usize> = stats.iter().map(|r| r.length).collect();
+
+    // the aggregate summary goes to stderr so stdout stays a single,
+    // directly-parseable TSV table
+    eprintln!("records: {}", stats.len());
+    eprintln!("total_length: {total_length}");
+    eprintln!("n50: {}", n50(&lengths));
+    eprintln!("gc_content: {overall_gc:.4}");
+
+    println!("id\tdesc\tlength\tgc_content\tA\tC\tG\tT\tN\tother");
+
+    for r in &stats {
+        println!(
+            "{}\t{}\t{}\t{:.4}\t{}\t{}\t{}\t{}\t{}\t{}",
+            r.id, r.desc, r.length, r.gc_content, r.a, r.c, r.g, r.t, r.n, r.other
+        );
+    }
+}
+
+// --------------------------------------------------
+// complements a single IUPAC nucleotide code, preserving case; an
+// unrecognized code is reported as an error rather than passed through
+fn complement(base: u8) -> MyResult<u8> {
+    let complement = match base.to_ascii_uppercase() {
+        b'A' => b'T',
+        b'T' => b'A',
+        b'C' => b'G',
+        b'G' => b'C',
+        b'R' => b'Y',
+        b'Y' => b'R',
+        b'S' => b'S',
+        b'W' => b'W',
+        b'K' => b'M',
+        b'M' => b'K',
+        b'B' => b'V',
+        b'V' => b'B',
+        b'D' => b'H',
+        b'H' => b'D',
+        b'N' => b'N',
+        other => return Err(format!("Unrecognized nucleotide: {}", other as char).into()),
+    };
+
+    Ok(if base.is_ascii_lowercase() {
+        complement.to_ascii_lowercase()
+    } else {
+        complement
+    })
+}
+
+// --------------------------------------------------
+fn reverse_complement(seq: &[u8]) -> MyResult<Vec<u8>> {
+    seq.iter().rev().map(|&base| complement(base)).collect()
+}
+
+// --------------------------------------------------
+fn transcribe(seq: &[u8]) -> Vec<u8> {
+    seq.iter()
+        .map(|&base| match base {
+            b'T' => b'U',
+            b't' => b'u',
+            other => other,
+        })
+        .collect()
+}
+
+// --------------------------------------------------
+fn write_records(records: &[SeqRecord], transform: impl Fn(&[u8]) -> MyResult<Vec<u8>>) -> MyResult<()> {
+    let mut writer = fasta::Writer::new(io::stdout());
+
+    for record in records {
+        let seq = transform(&record.seq)?;
+        writer.write(&record.id, record.desc.as_deref(), &seq)?;
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+fn run(config: Config) -> MyResult<()> {
+    let records = read_records(&config)?;
+
+    match config.mode {
+        Mode::Stats => {
+            print_stats(&records);
+            Ok(())
+        }
+        Mode::RevComp => write_records(&records, reverse_complement),
+        Mode::Transcribe => write_records(&records, |seq| Ok(transcribe(seq))),
+    }
+}
+
+// --------------------------------------------------
 fn main() {
-    let f = File::open("data/test.fa").unwrap();
-    let reader = fasta::Reader::new(BufReader::new(f));
+    let config = get_args();
 
-    let mut entries = 0;
+    if let Err(e) = run(config) {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
+
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_stats_counts_and_gc_content() {
+        let (a, c, g, t, n, other, gc_content) = base_stats(b"ACGTN");
+        assert_eq!((a, c, g, t, n, other), (1, 1, 1, 1, 1, 0));
+        assert_eq!(gc_content, 0.5);
+    }
+
+    #[test]
+    fn base_stats_ignores_ambiguous_codes_in_gc_content() {
+        let (_, _, _, _, _, other, gc_content) = base_stats(b"GGCCRY");
+        assert_eq!(other, 2);
+        assert_eq!(gc_content, 1.0);
+    }
+
+    #[test]
+    fn base_stats_of_empty_seq_has_no_gc_content() {
+        let (_, _, _, _, _, _, gc_content) = base_stats(b"");
+        assert_eq!(gc_content, 0.0);
+    }
+
+    #[test]
+    fn n50_of_single_length() {
+        assert_eq!(n50(&[100]), 100);
+    }
+
+    #[test]
+    fn n50_picks_the_length_at_the_halfway_point() {
+        // sorted descending: 50, 30, 20; total 100, half 50
+        // running sum reaches 50 at the first record
+        assert_eq!(n50(&[20, 50, 30]), 50);
+    }
+
+    #[test]
+    fn n50_of_equal_lengths() {
+        assert_eq!(n50(&[8, 8]), 8);
+    }
 
-    for result in reader.records() {
-        let record = result.expect("Error during FASTA record parsing");
-        entries += 1;
-        let nb_bases = record.seq().len();
-        println!("ID: {}", record.id());
-        println!("Description: {}", record.desc().unwrap());
-        println!("Number of bases: {}", nb_bases);
+    #[test]
+    fn reverse_complement_handles_iupac_codes() {
+        assert_eq!(reverse_complement(b"ACGTRYN").unwrap(), b"NRYACGT");
     }
 
-    println!("Total number of entries: {}", entries);
+    #[test]
+    fn reverse_complement_preserves_case() {
+        assert_eq!(reverse_complement(b"acgt").unwrap(), b"acgt");
+        assert_eq!(reverse_complement(b"AcGt").unwrap(), b"aCgT");
+    }
+
+    #[test]
+    fn reverse_complement_rejects_unrecognized_nucleotide() {
+        assert!(reverse_complement(b"ACGZT").is_err());
+    }
+
+    #[test]
+    fn transcribe_converts_t_to_u_and_preserves_case() {
+        assert_eq!(transcribe(b"ACGTacgt"), b"ACGUacgu");
+    }
 }
@@ -0,0 +1,18 @@
+use assert_cmd::Command;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+// --------------------------------------------------
+#[test]
+fn stats_emits_a_tsv_table() -> TestResult {
+    let expected = "id\tdesc\tlength\tgc_content\tA\tC\tG\tT\tN\tother\n\
+seq1\tdesc1\t8\t0.5000\t2\t2\t2\t2\t0\t0\n\
+seq2\t\t8\t1.0000\t0\t4\t4\t0\t0\t0\n";
+
+    Command::cargo_bin("fastartools")?
+        .args(["tests/data/sample.fa", "--format", "fasta"])
+        .assert()
+        .success()
+        .stdout(expected);
+    Ok(())
+}